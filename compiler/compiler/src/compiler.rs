@@ -72,15 +72,35 @@ impl<'a> Compiler<'a> {
         }
     }
 
-    /// Returns a SHA256 checksum of the program file.
+    /// Returns a SHA256 checksum of the parsed program and its imports, together with the compiler version,
+    /// the network, and the build options. Programs with the same checksum compile to the same Aleo instructions.
     pub fn checksum(&self) -> Result<String> {
-        // Read in the main file as string
-        let unparsed_file = fs::read_to_string(&self.main_file_path)
-            .map_err(|e| CompilerError::file_read_error(self.main_file_path.clone(), e))?;
+        // Prefix each field with its length, so that different fields can't produce the same input to the hash.
+        fn update(hasher: &mut Sha256, bytes: &[u8]) {
+            hasher.update((bytes.len() as u64).to_le_bytes());
+            hasher.update(bytes);
+        }
+
+        // Hash the source of each program scope rather than the AST, whose spans depend on the order of loaded files.
+        fn hash_program_source(hasher: &mut Sha256, program: &Program) -> Result<()> {
+            for (name, (import, _)) in program.imports.iter() {
+                update(hasher, name.to_string().as_bytes());
+                hash_program_source(hasher, import)?;
+            }
+            for scope in program.program_scopes.values() {
+                let source = with_session_globals(|s| s.source_map.contents_of_span(scope.span)).ok_or_else(|| {
+                    CompilerError::failed_to_read_program_scope_source(scope.program_id.name, scope.span)
+                })?;
+                update(hasher, source.as_bytes());
+            }
+            Ok(())
+        }
 
-        // Hash the file contents
         let mut hasher = Sha256::new();
-        hasher.update(unparsed_file.as_bytes());
+        update(&mut hasher, env!("CARGO_PKG_VERSION").as_bytes());
+        update(&mut hasher, self.network.as_bytes());
+        update(&mut hasher, &[self.compiler_options.build.dce_enabled as u8]);
+        hash_program_source(&mut hasher, self.ast.as_repr())?;
         let hash = hasher.finalize();
 
         Ok(format!("{hash:x}"))
@@ -281,3 +301,4 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 }
+
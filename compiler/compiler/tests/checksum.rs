@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{BuildOptions, Compiler, CompilerOptions};
+use leo_errors::emitter::Handler;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const FOO: &str = "program foo.aleo {\n    transition main(a: u32) -> u32 {\n        return a;\n    }\n}\n";
+
+/// Restores the current working directory when dropped.
+struct CwdGuard(PathBuf);
+
+impl CwdGuard {
+    fn set(path: &Path) -> Self {
+        let guard = Self(std::env::current_dir().unwrap());
+        std::env::set_current_dir(path).unwrap();
+        guard
+    }
+}
+
+impl Drop for CwdGuard {
+    fn drop(&mut self) {
+        std::env::set_current_dir(&self.0).unwrap();
+    }
+}
+
+fn checksum(file_path: &Path, program_name: &str, network: &str, dce_enabled: bool) -> String {
+    let handler = Handler::default();
+    let options = CompilerOptions { build: BuildOptions { dce_enabled }, ..Default::default() };
+    let mut compiler = Compiler::new(
+        program_name.to_string(),
+        network.to_string(),
+        &handler,
+        file_path.to_path_buf(),
+        PathBuf::new(),
+        Some(options),
+    );
+    compiler.parse_program().unwrap();
+    compiler.checksum().unwrap()
+}
+
+#[test]
+fn test_checksum() {
+    create_session_if_not_set_then(|_| {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("main.leo");
+        fs::write(&path, FOO).unwrap();
+
+        // The same program has the same checksum, even when it is loaded at a different position in the source map.
+        let expected = checksum(&path, "foo", "aleo", false);
+        assert_eq!(checksum(&path, "foo", "aleo", false), expected);
+
+        // Changing the network or the build options changes the checksum.
+        assert_ne!(checksum(&path, "foo", "testnet", false), expected);
+        assert_ne!(checksum(&path, "foo", "aleo", true), expected);
+
+        // Changing the program changes the checksum.
+        fs::write(&path, FOO.replace("return a;", "return a + a;")).unwrap();
+        assert_ne!(checksum(&path, "foo", "aleo", false), expected);
+    })
+}
+
+#[test]
+fn test_checksum_with_imports() {
+    create_session_if_not_set_then(|_| {
+        let directory = tempfile::tempdir().unwrap();
+        fs::create_dir(directory.path().join("imports")).unwrap();
+
+        // Imports are resolved relative to the current working directory.
+        // The guard is declared after `directory`, so the working directory is restored before it is removed.
+        let _cwd = CwdGuard::set(directory.path());
+
+        let main_path = directory.path().join("main.leo");
+        let import_path = directory.path().join("imports").join("bar.leo");
+        fs::write(&main_path, format!("import bar.leo;\n{FOO}")).unwrap();
+        fs::write(&import_path, FOO.replace("foo", "bar")).unwrap();
+
+        let main_checksum = checksum(&main_path, "foo", "aleo", false);
+        let import_checksum = checksum(&import_path, "bar", "aleo", false);
+
+        // Changing the length of the main program doesn't change the checksum of the import.
+        fs::write(&main_path, format!("import bar.leo;\n{}", FOO.replace("return a;", "return a + a;"))).unwrap();
+        assert_ne!(checksum(&main_path, "foo", "aleo", false), main_checksum);
+        assert_eq!(checksum(&import_path, "bar", "aleo", false), import_checksum);
+
+        // Changing the import changes the checksum of the main program.
+        fs::write(&main_path, format!("import bar.leo;\n{FOO}")).unwrap();
+        assert_eq!(checksum(&main_path, "foo", "aleo", false), main_checksum);
+        fs::write(&import_path, FOO.replace("foo", "bar").replace("return a;", "return a * a;")).unwrap();
+        assert_ne!(checksum(&main_path, "foo", "aleo", false), main_checksum);
+    })
+}
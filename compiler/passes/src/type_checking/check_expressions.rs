@@ -20,7 +20,7 @@ use leo_ast::*;
 use leo_errors::{emitter::Handler, TypeCheckerError};
 use leo_span::{sym, Span};
 
-use std::str::FromStr;
+use std::{collections::HashSet, str::FromStr};
 
 fn return_incorrect_type(t1: Option<Type>, t2: Option<Type>, expected: &Option<Type>) -> Option<Type> {
     match (t1, t2) {
@@ -267,9 +267,12 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
 
+                // Check that the divisor is not a literal zero.
+                self.assert_nonzero_divisor(input.op, &input.right);
+
                 return_incorrect_type(t1, t2, destination)
             }
-            BinaryOperation::Rem | BinaryOperation::RemWrapped => {
+            BinaryOperation::DivWrapped | BinaryOperation::Rem | BinaryOperation::RemWrapped => {
                 // Only integer types.
                 self.assert_int_type(destination, input.span());
 
@@ -279,6 +282,9 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
 
+                // Check that the divisor is not a literal zero.
+                self.assert_nonzero_divisor(input.op, &input.right);
+
                 return_incorrect_type(t1, t2, destination)
             }
             BinaryOperation::Mod => {
@@ -291,6 +297,9 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
 
+                // Check that the divisor is not a literal zero.
+                self.assert_nonzero_divisor(input.op, &input.right);
+
                 return_incorrect_type(t1, t2, destination)
             }
             BinaryOperation::Pow => {
@@ -375,10 +384,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
                 Some(Type::Boolean)
             }
-            BinaryOperation::AddWrapped
-            | BinaryOperation::SubWrapped
-            | BinaryOperation::DivWrapped
-            | BinaryOperation::MulWrapped => {
+            BinaryOperation::AddWrapped | BinaryOperation::SubWrapped | BinaryOperation::MulWrapped => {
                 // Only integer types.
                 self.assert_int_type(destination, input.span);
                 let t1 = self.visit_expression(&input.left, destination);
@@ -490,16 +496,35 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 ));
             }
 
+            // Check that each initialized member is unique and belongs to the struct.
+            let mut initialized = HashSet::new();
+            input.members.iter().for_each(|initializer| {
+                let identifier = &initializer.identifier;
+                let member = struct_.members.iter().find(|member| member.name() == identifier.name);
+                if !initialized.insert(identifier.name) {
+                    self.emit_err(TypeCheckerError::duplicate_struct_init_member(
+                        struct_.identifier,
+                        identifier,
+                        identifier.span(),
+                    ));
+                    // The member type check below only visits the first initializer, so check the duplicate here.
+                    self.visit_struct_variable_initializer(initializer, &member.map(|member| member.type_.clone()));
+                } else if member.is_none() {
+                    self.emit_err(TypeCheckerError::invalid_struct_variable(
+                        identifier,
+                        struct_.identifier,
+                        identifier.span(),
+                    ));
+                    // The member has no type to check against, but its expression must still be well-formed.
+                    self.visit_struct_variable_initializer(initializer, &None);
+                }
+            });
+
             // Check struct member types.
             struct_.members.iter().for_each(|Member { identifier, type_, .. }| {
                 // Lookup struct variable name.
                 if let Some(actual) = input.members.iter().find(|member| member.identifier.name == identifier.name) {
-                    match &actual.expression {
-                        // If `expression` is None, then the member uses the identifier shorthand, e.g. `Foo { a }`
-                        None => self.visit_identifier(&actual.identifier, &Some(type_.clone())),
-                        // Otherwise, visit the associated expression.
-                        Some(expr) => self.visit_expression(expr, &Some(type_.clone())),
-                    };
+                    self.visit_struct_variable_initializer(actual, &Some(type_.clone()));
                 } else {
                     self.emit_err(TypeCheckerError::missing_struct_member(
                         struct_.identifier,
@@ -16,7 +16,20 @@
 
 use crate::{CallGraph, StructGraph, SymbolTable};
 
-use leo_ast::{CoreFunction, Identifier, IntegerType, MappingType, Node, Type, Variant};
+use leo_ast::{
+    BinaryOperation,
+    CoreFunction,
+    Expression,
+    ExpressionVisitor,
+    Identifier,
+    IntegerType,
+    Literal,
+    MappingType,
+    Node,
+    StructVariableInitializer,
+    Type,
+    Variant,
+};
 use leo_errors::{emitter::Handler, TypeCheckerError};
 use leo_span::{Span, Symbol};
 
@@ -163,6 +176,30 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Type checks the value of a struct member initializer against the expected type.
+    pub(crate) fn visit_struct_variable_initializer(
+        &mut self,
+        initializer: &'a StructVariableInitializer,
+        expected: &Option<Type>,
+    ) {
+        match &initializer.expression {
+            // If `expression` is None, then the member uses the identifier shorthand, e.g. `Foo { a }`
+            None => self.visit_identifier(&initializer.identifier, expected),
+            // Otherwise, visit the associated expression.
+            Some(expr) => self.visit_expression(expr, expected),
+        };
+    }
+
+    /// Emits an error to the handler if the given divisor of `operator` is a literal zero.
+    pub(crate) fn assert_nonzero_divisor(&self, operator: BinaryOperation, divisor: &Expression) {
+        if let Expression::Literal(Literal::Field(value, span) | Literal::Integer(_, value, span)) = divisor {
+            // The parser folds a negated literal into its value, e.g. `-0i32` has the value `-0`.
+            if value.trim_start_matches('-').chars().all(|c| c == '0') {
+                self.emit_err(TypeCheckerError::division_by_zero(operator, *span));
+            }
+        }
+    }
+
     /// Use this method when you know the actual type.
     /// Emits an error to the handler if the `actual` type is not equal to the `expected` type.
     pub(crate) fn assert_and_return_type(&self, actual: Type, expected: &Option<Type>, span: Span) -> Type {
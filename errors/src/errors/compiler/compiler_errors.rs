@@ -70,4 +70,11 @@ create_messages!(
         msg: format!("The program scope name `{program_scope_name}` must match `{file_name}`."),
         help: None,
     }
+
+    @formatted
+    failed_to_read_program_scope_source {
+        args: (program_scope_name: impl Display),
+        msg: format!("Failed to read the source of program scope `{program_scope_name}`."),
+        help: None,
+    }
 );
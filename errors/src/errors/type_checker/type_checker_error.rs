@@ -601,4 +601,18 @@ create_messages!(
         msg: format!("The number of mappings exceeds the maximum. snarkVM allows up to {max} mappings within a single program."),
         help: None,
     }
+
+    @formatted
+    duplicate_struct_init_member {
+        args: (struct_: impl Display, member: impl Display),
+        msg: format!("Struct initialization expression for `{struct_}` initializes member `{member}` more than once."),
+        help: None,
+    }
+
+    @formatted
+    division_by_zero {
+        args: (operator: impl Display),
+        msg: format!("The divisor of `{operator}` is the constant zero."),
+        help: Some("Division and remainder by zero always fail at execution time.".to_string()),
+    }
 );
@@ -26,7 +26,7 @@ use leo_package::{
     build::BuildDirectory,
     imports::ImportsDirectory,
     inputs::InputFile,
-    outputs::OutputsDirectory,
+    outputs::{ChecksumFile, OutputsDirectory},
     source::SourceDirectory,
 };
 use leo_span::{symbol::with_session_globals, Symbol};
@@ -192,7 +192,7 @@ impl Command for Build {
 
             // TODO: This is a hack to notify the user that something is wrong with the input file. Redesign.
             leo_parser::parse_input(&handler, &input_sf.src, input_sf.start_pos)
-                .map_err(|_e| println!("Warning: Failed to parse input file"))
+                .map_err(|_e| tracing::warn!("Failed to parse input file"))
                 .ok()
         } else {
             None
@@ -222,7 +222,7 @@ impl Command for Build {
 
 /// Compiles a Leo file in the `src/` directory.
 #[allow(clippy::too_many_arguments)]
-fn compile_leo_file(
+pub(crate) fn compile_leo_file(
     file_path: PathBuf,
     _package_path: &Path,
     program_id: &ProgramID<Testnet3>,
@@ -250,6 +250,17 @@ fn compile_leo_file(
         false => format!("main.{}", program_id.network()),
     });
 
+    // Create the checksum file for the program.
+    let checksum_file = ChecksumFile::new(&program_name);
+
+    // AST snapshots of the later compiler phases are only written on a full compilation.
+    let compiler_options = CompilerOptions::from(options);
+    let snapshots_enabled = compiler_options.output.unrolled_ast
+        || compiler_options.output.ssa_ast
+        || compiler_options.output.flattened_ast
+        || compiler_options.output.inlined_ast
+        || compiler_options.output.dce_ast;
+
     // Create a new instance of the Leo compiler.
     let mut compiler = Compiler::new(
         program_name,
@@ -257,11 +268,35 @@ fn compile_leo_file(
         handler,
         file_path.clone(),
         outputs.to_path_buf(),
-        Some(options.into()),
+        Some(compiler_options),
     );
 
+    // Parse the program and compute its checksum. The checksum is only a cache key, so failing to compute, read,
+    // or write it never fails the build. Without a checksum, the program is always compiled.
+    compiler.parse_program()?;
+    let checksum = compiler.checksum().ok();
+
+    // If the program is unchanged since the last build, reuse the existing Aleo instructions.
+    if let Some(checksum) = &checksum {
+        if !snapshots_enabled
+            && aleo_file_path.exists()
+            && checksum_file.read_from(outputs).ok().as_ref() == Some(checksum)
+        {
+            tracing::info!("'{}' is unchanged, skipping compilation", file_name);
+
+            // Type check the program so that its warnings are still reported.
+            let (symbol_table, ..) = compiler.type_checker_pass(compiler.symbol_table_pass()?)?;
+
+            return Ok(symbol_table.structs);
+        }
+    }
+
     // Compile the Leo program into Aleo instructions.
-    let (symbol_table, instructions) = compiler.compile()?;
+    let (symbol_table, struct_graph, call_graph) = compiler.compiler_stages()?;
+    let instructions = compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph)?;
+
+    // Remove the previous checksum, so that it can't be paired with partially written or newer instructions.
+    let _ = checksum_file.remove(outputs);
 
     // Write the instructions.
     std::fs::File::create(&aleo_file_path)
@@ -269,6 +304,13 @@ fn compile_leo_file(
         .write_all(instructions.as_bytes())
         .map_err(CliError::failed_to_load_instructions)?;
 
+    // Write the checksum of the compiled program.
+    if let Some(checksum) = checksum {
+        if checksum_file.write_to(outputs, checksum).is_err() {
+            tracing::warn!("Failed to write the checksum of '{}'", file_name);
+        }
+    }
+
     // Prepare the path string.
     let _path_string = format!("(in \"{}\")", aleo_file_path.display());
 
@@ -277,3 +319,4 @@ fn compile_leo_file(
 
     Ok(symbol_table.structs)
 }
+
@@ -14,7 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_errors::Result;
+use crate::commands::build::{compile_leo_file, BuildOptions};
+use leo_errors::{emitter::Handler, Result};
+use leo_package::outputs::{ChecksumFile, OutputsDirectory};
+use leo_span::symbol::create_session_if_not_set_then;
+
+use snarkvm::prelude::{ProgramID, Testnet3};
+use std::{fs, str::FromStr};
 // use std::path::PathBuf;
 
 /* use crate::{
@@ -44,6 +50,62 @@ pub fn format_event() -> Result<()> {
     Ok(())
 }
 
+#[test]
+pub fn compile_leo_file_reuses_unchanged_program() -> Result<()> {
+    const PROGRAM: &str = "program foo.aleo {\n    transition main(a: u32) -> u32 {\n        return a;\n    }\n}\n";
+
+    create_session_if_not_set_then(|_| {
+        // Create a package with a single source file.
+        let directory = tempfile::tempdir().unwrap();
+        let package_path = directory.path();
+        let source_path = package_path.join("main.leo");
+        let build = package_path.join("build");
+        let aleo_file_path = build.join("main.aleo");
+        fs::create_dir(&build).unwrap();
+        fs::write(&source_path, PROGRAM).unwrap();
+
+        let outputs = OutputsDirectory::create(package_path)?;
+        let program_id = ProgramID::<Testnet3>::from_str("foo.aleo").unwrap();
+        let handler = Handler::default();
+        let compile = || {
+            compile_leo_file(
+                source_path.clone(),
+                package_path,
+                &program_id,
+                &outputs,
+                &build,
+                &handler,
+                BuildOptions::default(),
+                false,
+            )
+        };
+
+        // The first build compiles the program and records its checksum.
+        compile()?;
+        assert!(fs::read_to_string(&aleo_file_path).unwrap().contains("input r0 as u32.private;"));
+        assert!(ChecksumFile::new("foo").exists_at(&outputs));
+
+        // An unchanged program reuses the existing Aleo file.
+        fs::write(&aleo_file_path, "cached").unwrap();
+        compile()?;
+        assert_eq!(fs::read_to_string(&aleo_file_path).unwrap(), "cached");
+
+        // A changed program is compiled again.
+        fs::write(&source_path, PROGRAM.replace("return a;", "return a + a;")).unwrap();
+        compile()?;
+        assert!(fs::read_to_string(&aleo_file_path).unwrap().contains("add r0 r0 into r1;"));
+
+        // An unreadable checksum is a cache miss rather than an error.
+        fs::remove_file(package_path.join("outputs").join("foo.sum")).unwrap();
+        fs::create_dir(package_path.join("outputs").join("foo.sum")).unwrap();
+        fs::write(&aleo_file_path, "cached").unwrap();
+        compile()?;
+        assert_ne!(fs::read_to_string(&aleo_file_path).unwrap(), "cached");
+
+        Ok(())
+    })
+}
+
 // todo (collin): uncomment after refactor
 // #[test]
 // pub fn build_pedersen_hash() -> Result<()> {